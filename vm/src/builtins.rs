@@ -0,0 +1,30 @@
+/*
+ * Implementations of the global builtin functions that hand off to the
+ * iterator machinery in `obj::objiter`.
+ */
+
+use crate::function::OptionalArg;
+use crate::obj::objiter;
+use crate::pyobject::{PyObjectRef, PyResult};
+use crate::vm::VirtualMachine;
+
+pub fn builtin_iter(
+    iter_target: PyObjectRef,
+    sentinel: OptionalArg<PyObjectRef>,
+    vm: &VirtualMachine,
+) -> PyResult {
+    if let OptionalArg::Present(sentinel) = sentinel {
+        objiter::get_iter_two_args(vm, iter_target, sentinel)
+    } else {
+        objiter::get_iter(vm, &iter_target)
+    }
+}
+
+pub fn builtin_reversed(obj: PyObjectRef, vm: &VirtualMachine) -> PyResult {
+    if let Some(reversed_method_or_err) = vm.get_method(obj.clone(), "__reversed__") {
+        let reversed_method = reversed_method_or_err?;
+        vm.invoke(&reversed_method, vec![])
+    } else {
+        objiter::get_reversed_iter(vm, &obj)
+    }
+}
@@ -27,34 +27,77 @@ pub fn get_iter(vm: &VirtualMachine, iter_target: &PyObjectRef) -> PyResult {
         vm.get_method_or_type_error(iter_target.clone(), "__getitem__", || {
             format!("Cannot iterate over {}", iter_target.class().name)
         })?;
-        let obj_iterator = PySequenceIterator {
-            position: Cell::new(0),
-            obj: iter_target.clone(),
-            reversed: false,
-        };
+        let obj_iterator = PySequenceIterator::new_forward(iter_target.clone());
         Ok(obj_iterator.into_ref(vm).into_object())
     }
 }
 
-pub fn call_next(vm: &VirtualMachine, iter_obj: &PyObjectRef) -> PyResult {
-    vm.call_method(iter_obj, "__next__", vec![])
+/*
+ * Called by the `reversed()` builtin when `iter_target` has no `__reversed__`
+ * of its own: falls back to the `__len__`/`__getitem__` sequence protocol,
+ * same as `get_iter` does for forward iteration.
+ */
+pub fn get_reversed_iter(vm: &VirtualMachine, iter_target: &PyObjectRef) -> PyResult {
+    vm.get_method_or_type_error(iter_target.clone(), "__getitem__", || {
+        format!("'{}' object is not reversible", iter_target.class().name)
+    })?;
+    let obj_iterator = PySequenceIterator::new_reversed(iter_target.clone(), vm)?;
+    Ok(obj_iterator.into_ref(vm).into_object())
 }
 
 /*
- * Helper function to retrieve the next object (or none) from an iterator.
+ * Shared by the forward and reverse sequence iterators: turn the container's
+ * length into a plain `usize`, producing the same `TypeError` CPython raises
+ * for an unsized object.
  */
-pub fn get_next_object(
+fn seq_len(obj: &PyObjectRef, vm: &VirtualMachine) -> PyResult<usize> {
+    objsequence::opt_len(obj, vm).unwrap_or_else(|| {
+        Err(vm.new_type_error(format!(
+            "object of type '{}' has no len()",
+            obj.class().name
+        )))
+    })
+}
+
+pub fn call_next(vm: &VirtualMachine, iter_obj: &PyObjectRef) -> PyResult {
+    vm.call_method(iter_obj, "__next__", vec![])
+}
+
+/* The outcome of a `__next__` call: a value, or a stop carrying an optional return value. */
+#[derive(Debug)]
+pub enum PyIterReturn {
+    Return(PyObjectRef),
+    StopIteration(Option<PyObjectRef>),
+}
+
+impl PyIterReturn {
+    pub fn into_pyresult(self, vm: &VirtualMachine) -> PyResult {
+        match self {
+            PyIterReturn::Return(obj) => Ok(obj),
+            PyIterReturn::StopIteration(v) => Err(new_stop_iteration_with_value(vm, v)),
+        }
+    }
+}
+
+fn new_stop_iteration_with_value(
     vm: &VirtualMachine,
-    iter_obj: &PyObjectRef,
-) -> PyResult<Option<PyObjectRef>> {
-    let next_obj: PyResult = call_next(vm, iter_obj);
+    value: Option<PyObjectRef>,
+) -> PyBaseExceptionRef {
+    match value {
+        Some(value) => vm.new_exception(vm.ctx.exceptions.stop_iteration.clone(), vec![value]),
+        None => new_stop_iteration(vm),
+    }
+}
 
-    match next_obj {
-        Ok(value) => Ok(Some(value)),
+/* Call `__next__`, translating a raised `StopIteration` into `PyIterReturn::StopIteration`. */
+pub fn get_next_or_return(vm: &VirtualMachine, iter_obj: &PyObjectRef) -> PyResult<PyIterReturn> {
+    match call_next(vm, iter_obj) {
+        Ok(value) => Ok(PyIterReturn::Return(value)),
         Err(next_error) => {
-            // Check if we have stopiteration, or something else:
             if objtype::isinstance(&next_error, &vm.ctx.exceptions.stop_iteration) {
-                Ok(None)
+                // Bare `StopIteration()` (no args) must stay `None`, not `Some(none_obj)`.
+                let value = next_error.args().elements.first().cloned();
+                Ok(PyIterReturn::StopIteration(value))
             } else {
                 Err(next_error)
             }
@@ -62,9 +105,28 @@ pub fn get_next_object(
     }
 }
 
+/*
+ * Helper function to retrieve the next object (or none) from an iterator.
+ */
+pub fn get_next_object(
+    vm: &VirtualMachine,
+    iter_obj: &PyObjectRef,
+) -> PyResult<Option<PyObjectRef>> {
+    match get_next_or_return(vm, iter_obj)? {
+        PyIterReturn::Return(value) => Ok(Some(value)),
+        PyIterReturn::StopIteration(_) => Ok(None),
+    }
+}
+
+// `__length_hint__` is advisory, not authoritative: a malicious or buggy one
+// returning a huge value shouldn't turn into a giant up-front allocation.
+const MAX_PREALLOCATE: usize = 4096;
+
 /* Retrieve all elements from an iterator */
 pub fn get_all<T: TryFromObject>(vm: &VirtualMachine, iter_obj: &PyObjectRef) -> PyResult<Vec<T>> {
-    let cap = length_hint(vm, iter_obj.clone())?.unwrap_or(0);
+    let cap = length_hint(vm, iter_obj.clone())?
+        .unwrap_or(0)
+        .min(MAX_PREALLOCATE);
     let mut elements = Vec::with_capacity(cap);
     while let Some(element) = get_next_object(vm, iter_obj)? {
         elements.push(T::try_from_object(vm, element)?);
@@ -131,6 +193,40 @@ pub fn length_hint(vm: &VirtualMachine, iter: PyObjectRef) -> PyResult<Option<us
     Ok(Some(hint))
 }
 
+/* Shared isize position arithmetic for the forward and reverse sequence-iteration paths. */
+struct PositionIndexer;
+
+impl PositionIndexer {
+    fn start(reversed: bool, len: usize) -> isize {
+        if reversed {
+            len as isize - 1
+        } else {
+            0
+        }
+    }
+
+    fn step(reversed: bool) -> isize {
+        if reversed {
+            -1
+        } else {
+            1
+        }
+    }
+
+    fn remaining(position: isize, reversed: bool, len: usize) -> usize {
+        if position < 0 {
+            return 0;
+        }
+        let position = position as usize;
+        let remaining = if reversed {
+            position + 1
+        } else {
+            len.saturating_sub(position)
+        };
+        remaining.min(len)
+    }
+}
+
 #[pyclass]
 #[derive(Debug)]
 pub struct PySequenceIterator {
@@ -147,41 +243,142 @@ impl PyValue for PySequenceIterator {
 
 #[pyimpl]
 impl PySequenceIterator {
-    #[pymethod(name = "__next__")]
-    fn next(&self, vm: &VirtualMachine) -> PyResult {
+    pub fn new_forward(obj: PyObjectRef) -> Self {
+        PySequenceIterator {
+            position: Cell::new(0),
+            obj,
+            reversed: false,
+        }
+    }
+
+    /*
+     * Seed `position` at `len - 1` so the first `__next__` call yields the
+     * container's last element, then walk downward until `position < 0`.
+     */
+    pub fn new_reversed(obj: PyObjectRef, vm: &VirtualMachine) -> PyResult<Self> {
+        let len = seq_len(&obj, vm)?;
+        Ok(PySequenceIterator {
+            position: Cell::new(PositionIndexer::start(true, len)),
+            obj,
+            reversed: true,
+        })
+    }
+
+    /* Like `__next__`, but returns `PyIterReturn` directly for native callers. */
+    pub(crate) fn next_returned(&self, vm: &VirtualMachine) -> PyResult<PyIterReturn> {
         if self.position.get() >= 0 {
-            let step: isize = if self.reversed { -1 } else { 1 };
+            let step = PositionIndexer::step(self.reversed);
             let number = vm.ctx.new_int(self.position.get());
             match vm.call_method(&self.obj, "__getitem__", vec![number]) {
                 Ok(val) => {
                     self.position.set(self.position.get() + step);
-                    Ok(val)
+                    Ok(PyIterReturn::Return(val))
                 }
                 Err(ref e) if objtype::isinstance(&e, &vm.ctx.exceptions.index_error) => {
-                    Err(new_stop_iteration(vm))
+                    Ok(PyIterReturn::StopIteration(None))
                 }
                 // also catches stop_iteration => stop_iteration
                 Err(e) => Err(e),
             }
         } else {
-            Err(new_stop_iteration(vm))
+            Ok(PyIterReturn::StopIteration(None))
         }
     }
 
+    #[pymethod(name = "__next__")]
+    fn next(&self, vm: &VirtualMachine) -> PyResult {
+        self.next_returned(vm)?.into_pyresult(vm)
+    }
+
     #[pymethod(name = "__iter__")]
     fn iter(zelf: PyRef<Self>, _vm: &VirtualMachine) -> PyRef<Self> {
         zelf
     }
+
+    #[pymethod(name = "__length_hint__")]
+    fn length_hint(&self, vm: &VirtualMachine) -> PyResult<usize> {
+        let pos = self.position.get();
+        if pos < 0 {
+            return Ok(0);
+        }
+        let len = seq_len(&self.obj, vm)?;
+        Ok(PositionIndexer::remaining(pos, self.reversed, len))
+    }
 }
 
 pub fn seq_iter_method(obj: PyObjectRef, _vm: &VirtualMachine) -> PySequenceIterator {
-    PySequenceIterator {
-        position: Cell::new(0),
-        obj,
-        reversed: false,
+    PySequenceIterator::new_forward(obj)
+}
+
+/*
+ * The two-argument form of the `iter()` builtin: repeatedly calls `callable`
+ * with no arguments until it returns something equal to `sentinel`.
+ */
+#[pyclass]
+#[derive(Debug)]
+pub struct PyCallableIterator {
+    callable: PyObjectRef,
+    sentinel: PyObjectRef,
+    done: Cell<bool>,
+}
+
+impl PyValue for PyCallableIterator {
+    fn class(vm: &VirtualMachine) -> PyClassRef {
+        vm.ctx.types.callable_iterator_type.clone()
+    }
+}
+
+#[pyimpl]
+impl PyCallableIterator {
+    fn new(callable: PyObjectRef, sentinel: PyObjectRef) -> Self {
+        PyCallableIterator {
+            callable,
+            sentinel,
+            done: Cell::new(false),
+        }
     }
+
+    pub(crate) fn next_returned(&self, vm: &VirtualMachine) -> PyResult<PyIterReturn> {
+        if self.done.get() {
+            return Ok(PyIterReturn::StopIteration(None));
+        }
+        let value = vm.invoke(&self.callable, vec![])?;
+        if vm.bool_eq(&value, &self.sentinel)? {
+            self.done.set(true);
+            Ok(PyIterReturn::StopIteration(None))
+        } else {
+            Ok(PyIterReturn::Return(value))
+        }
+    }
+
+    #[pymethod(name = "__next__")]
+    fn next(&self, vm: &VirtualMachine) -> PyResult {
+        self.next_returned(vm)?.into_pyresult(vm)
+    }
+
+    #[pymethod(name = "__iter__")]
+    fn iter(zelf: PyRef<Self>, _vm: &VirtualMachine) -> PyRef<Self> {
+        zelf
+    }
+}
+
+/* Backs the `iter(callable, sentinel)` form of the `iter()` builtin. */
+pub fn get_iter_two_args(
+    vm: &VirtualMachine,
+    callable: PyObjectRef,
+    sentinel: PyObjectRef,
+) -> PyResult {
+    vm.get_method_or_type_error(callable.clone(), "__call__", || {
+        format!(
+            "iter(v, w): v must be callable, not '{}'",
+            callable.class().name
+        )
+    })?;
+    let iterator = PyCallableIterator::new(callable, sentinel);
+    Ok(iterator.into_ref(vm).into_object())
 }
 
 pub fn init(context: &PyContext) {
     PySequenceIterator::extend_class(context, &context.types.iter_type);
+    PyCallableIterator::extend_class(context, &context.types.callable_iterator_type);
 }
@@ -0,0 +1,31 @@
+/*
+ * The `operator` module.
+ */
+
+use crate::function::OptionalArg;
+use crate::obj::objiter;
+use crate::pyobject::{PyContext, PyObjectRef, PyResult};
+use crate::vm::VirtualMachine;
+
+// `default` round-trips unchanged (even negative) when no length can be
+// determined, matching CPython: `operator.length_hint(object(), -5) == -5`.
+// This is why the return type is `isize`, not `usize` - a `usize` couldn't
+// represent that.
+fn operator_length_hint(
+    obj: PyObjectRef,
+    default: OptionalArg<isize>,
+    vm: &VirtualMachine,
+) -> PyResult<isize> {
+    let default = default.unwrap_or(0);
+    match objiter::length_hint(vm, obj)? {
+        Some(hint) => Ok(hint as isize),
+        None => Ok(default),
+    }
+}
+
+pub fn make_module(vm: &VirtualMachine) -> PyObjectRef {
+    let ctx = &vm.ctx;
+    py_module!(vm, "operator", {
+        "length_hint" => ctx.new_function(operator_length_hint),
+    })
+}